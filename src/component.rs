@@ -1,12 +1,104 @@
+use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::ext;
 use futures::FutureExt;
+use gloo_timers::callback::Interval;
 use wasm_bindgen::{prelude::Closure, JsValue};
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
-#[derive(Clone, Properties, PartialEq)]
+/// The playback-quality ladder the adaptive controller steps through, from
+/// lowest to highest. `Default`/`Auto` are left out since they aren't a
+/// rung on the ladder to step to or from.
+const QUALITY_LADDER: [ext::VideoQuality; 6] = [
+    ext::VideoQuality::Small,
+    ext::VideoQuality::Medium,
+    ext::VideoQuality::Large,
+    ext::VideoQuality::Hd720,
+    ext::VideoQuality::Hd1080,
+    ext::VideoQuality::Highres,
+];
+
+const ADAPTIVE_POLL_MS: u32 = 2_000;
+const ADAPTIVE_WINDOW_MS: f64 = 10_000.0;
+const ADAPTIVE_STEP_DOWN_THRESHOLD: usize = 2;
+
+const DEFAULT_PROGRESS_INTERVAL_MS: u32 = 250;
+
+fn start_progress_poller(ext_player: Rc<ext::Player>, ctx: &Context<Player>) -> Interval {
+    let link = ctx.link().clone();
+    let interval_ms = ctx
+        .props()
+        .progress_interval_ms
+        .unwrap_or(DEFAULT_PROGRESS_INTERVAL_MS);
+
+    Interval::new(interval_ms, move || {
+        link.send_message(Msg::Progress(ext::PlaybackProgress {
+            current: ext_player.get_current_time(),
+            duration: ext_player.get_duration(),
+            loaded_fraction: ext_player.get_video_loaded_fraction(),
+        }));
+    })
+}
+
+fn step_quality(
+    available: &[ext::VideoQuality],
+    current: ext::VideoQuality,
+    direction: i32,
+) -> Option<ext::VideoQuality> {
+    let mut rank = QUALITY_LADDER.iter().position(|q| *q == current)? as i32;
+    loop {
+        rank += direction;
+        let candidate = *QUALITY_LADDER.get(usize::try_from(rank).ok()?)?;
+        if available.contains(&candidate) {
+            return Some(candidate);
+        }
+    }
+}
+
+/// An imperative playback-control command, dispatched into a running `Player`
+/// via a [`PlayerHandle`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayerCommand {
+    Play,
+    Pause,
+    Stop,
+    SeekTo { seconds: f64, allow_seek_ahead: bool },
+    SetVolume(u8),
+    Mute,
+    Unmute,
+    SetPlaybackRate(f64),
+    LoadVideoById(String),
+}
+
+/// A handle a parent can hold onto to drive playback on a `Player` after it
+/// has been created, since Yew props can't carry a live reference back to a
+/// mounted component. Pass an empty handle down via `Props::handle`; the
+/// component fills it in when it is created. Commands sent after that but
+/// before the player reaches the `Ready` state are queued and flushed on
+/// entry into `Ready`; commands sent before the handle is filled in are
+/// dropped.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlayerHandle(Rc<RefCell<Option<Callback<PlayerCommand>>>>);
+
+impl PlayerHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn send(&self, command: PlayerCommand) {
+        if let Some(cb) = self.0.borrow().as_ref() {
+            cb.emit(command);
+        }
+    }
+
+    fn bind(&self, callback: Callback<PlayerCommand>) {
+        *self.0.borrow_mut() = Some(callback);
+    }
+}
+
+#[derive(Clone, Debug, Properties, PartialEq)]
 pub struct Props {
     pub video_id: String,
     pub width: Option<u32>,
@@ -14,6 +106,143 @@ pub struct Props {
     pub autoplay: Option<bool>,
 
     pub on_state_change: Option<Callback<ext::PlayerState>>,
+    pub on_error: Option<Callback<ext::PlayerError>>,
+    pub on_playback_quality_change: Option<Callback<ext::VideoQuality>>,
+    pub on_playback_rate_change: Option<Callback<f64>>,
+
+    pub suggested_quality: Option<ext::VideoQuality>,
+    #[prop_or_default]
+    pub adaptive_quality: bool,
+    pub on_quality_change: Option<Callback<ext::VideoQuality>>,
+
+    pub progress_interval_ms: Option<u32>,
+    pub on_progress: Option<Callback<ext::PlaybackProgress>>,
+
+    pub handle: Option<PlayerHandle>,
+}
+
+fn dispatch_command(ext_player: &ext::Player, command: PlayerCommand) {
+    match command {
+        PlayerCommand::Play => ext_player.play_video(),
+        PlayerCommand::Pause => ext_player.pause_video(),
+        PlayerCommand::Stop => ext_player.stop_video(),
+        PlayerCommand::SeekTo {
+            seconds,
+            allow_seek_ahead,
+        } => ext_player.seek_to(seconds, allow_seek_ahead),
+        PlayerCommand::SetVolume(volume) => ext_player.set_volume(volume),
+        PlayerCommand::Mute => ext_player.mute(),
+        PlayerCommand::Unmute => ext_player.un_mute(),
+        PlayerCommand::SetPlaybackRate(rate) => ext_player.set_playback_rate(rate),
+        PlayerCommand::LoadVideoById(video_id) => {
+            ext_player.load_video_by_id(JsValue::from_str(&video_id))
+        }
+    }
+}
+
+/// Starts a poller that nudges playback quality down when buffering
+/// repeats within a short window and back up once playback has gone a full
+/// window since the last change without buffering, clamped to whatever
+/// `getAvailableQualityLevels` currently reports. The step-up side needs its
+/// own cooldown rather than just a clear buffering window: the buffering
+/// events that caused the last step down stay in that window for up to
+/// `ADAPTIVE_WINDOW_MS` after they happened, so without a cooldown tied to
+/// the step itself, quality would ping back up the instant they age out -
+/// often immediately re-buffering on a link that only sustains one level
+/// down - and oscillate indefinitely.
+fn start_adaptive_quality(ext_player: Rc<ext::Player>, ctx: &Context<Player>) -> Interval {
+    let link = ctx.link().clone();
+    let buffering_events: Rc<RefCell<Vec<f64>>> = Rc::new(RefCell::new(Vec::new()));
+    let last_change: Rc<RefCell<Option<f64>>> = Rc::new(RefCell::new(None));
+
+    Interval::new(ADAPTIVE_POLL_MS, move || {
+        let now = js_sys::Date::now();
+        // An unrecognized state can't be classified as buffering/playing/
+        // anything else useful to the controller; same as an unrecognized
+        // quality string below, skip this tick rather than act on it.
+        let Ok(state) = ext_player.get_player_state() else {
+            return;
+        };
+
+        let buffering_count = {
+            let mut events = buffering_events.borrow_mut();
+            events.retain(|t| now - *t <= ADAPTIVE_WINDOW_MS);
+            if matches!(state, ext::PlayerState::Buffering) {
+                events.push(now);
+            }
+            events.len()
+        };
+
+        let Ok(current) = ext::VideoQuality::try_from(ext_player.get_playback_quality().as_str())
+        else {
+            return;
+        };
+        let available: Vec<ext::VideoQuality> = ext_player
+            .get_available_quality_levels()
+            .iter()
+            .filter_map(|v| ext::VideoQuality::try_from(v).ok())
+            .collect();
+
+        let settled = last_change
+            .borrow()
+            .map_or(true, |t| now - t >= ADAPTIVE_WINDOW_MS);
+
+        let next = if buffering_count >= ADAPTIVE_STEP_DOWN_THRESHOLD {
+            step_quality(&available, current, -1)
+        } else if matches!(state, ext::PlayerState::Playing) && buffering_count == 0 && settled {
+            step_quality(&available, current, 1)
+        } else {
+            None
+        };
+
+        if let Some(next) = next.filter(|q| *q != current) {
+            ext_player.set_playback_quality(next.as_str());
+            *last_change.borrow_mut() = Some(now);
+            link.send_message(Msg::QualityChange(next));
+        }
+    })
+}
+
+/// What, if anything, a prop update requires of an already-constructed
+/// player. Only the single most significant change is reported, since a
+/// `Reinit` makes any other difference moot - but a new video does *not*
+/// make a simultaneous size change redundant (`loadVideoById` doesn't
+/// resize the iframe), so `VideoId` carries the current size along so
+/// callers can still apply it.
+enum PropsDelta {
+    None,
+    VideoId {
+        video_id: String,
+        width: Option<u32>,
+        height: Option<u32>,
+    },
+    Size {
+        width: Option<u32>,
+        height: Option<u32>,
+    },
+    Reinit,
+}
+
+fn diff_props(old: &Props, new: &Props) -> PropsDelta {
+    // `autoplay` is only ever read at construction time (it's folded into
+    // `playerVars`), so there's no IFrame API call that can apply a changed
+    // value to a live player - it needs a fresh one.
+    if old.autoplay != new.autoplay {
+        PropsDelta::Reinit
+    } else if old.video_id != new.video_id {
+        PropsDelta::VideoId {
+            video_id: new.video_id.clone(),
+            width: new.width,
+            height: new.height,
+        }
+    } else if old.width != new.width || old.height != new.height {
+        PropsDelta::Size {
+            width: new.width,
+            height: new.height,
+        }
+    } else {
+        PropsDelta::None
+    }
 }
 
 pub enum State {
@@ -33,12 +262,34 @@ trait FsmState {
     fn transition(self, msg: Msg, ctx: &Context<Player>) -> PlayerState;
 }
 
-#[derive(Clone)]
-struct Uninitialized;
+#[derive(Clone, Default)]
+struct Uninitialized {
+    pending_commands: Vec<PlayerCommand>,
+    // Closures carried over from a player that's being torn down for
+    // reconstruction (see `PropsDelta::Reinit`). Event listener removal is
+    // unreliable (see the ISSUE note on `Ready`), so these are kept alive
+    // rather than dropped out from under a listener YT may still hold.
+    _stale_closures: Vec<Rc<Closure<dyn FnMut(JsValue)>>>,
+}
 
 impl FsmState for Uninitialized {
     fn transition(self, msg: Msg, ctx: &Context<Player>) -> PlayerState {
         match msg {
+            // `video_id`/`width`/`height`/`autoplay` are all read fresh from
+            // `ctx.props()` below, so there's nothing to stash here beyond
+            // the command queue already carried on `self`.
+            Msg::PropsChanged(_) => PlayerState::Uninitialized(self),
+            // A stale closure (or, for `Progress`, a poller whose `Interval`
+            // hasn't been dropped yet) from a player that was just torn down
+            // (see `Reinitializing`) can still fire before it's actually
+            // cleaned up. There's no player here yet for it to describe, so
+            // there's nothing to do but drop it on the floor.
+            Msg::PlayerStateChange(_)
+            | Msg::Error(_)
+            | Msg::PlaybackQualityChange(_)
+            | Msg::QualityChange(_)
+            | Msg::PlaybackRateChange(_)
+            | Msg::Progress(_) => PlayerState::Uninitialized(self),
             Msg::Initialized => {
                 let ext_player = ext::Player::new(
                     "youtube-player-placeholder",
@@ -62,11 +313,21 @@ impl FsmState for Uninitialized {
                 PlayerState::Initialized(Initialized {
                     ext_player: Rc::new(ext_player),
                     on_ready: closure_ready,
+                    pending_commands: self.pending_commands,
+                    _stale_closures: self._stale_closures,
+                })
+            }
+            Msg::Command(command) => {
+                let mut pending_commands = self.pending_commands;
+                pending_commands.push(command);
+                PlayerState::Uninitialized(Uninitialized {
+                    pending_commands,
+                    _stale_closures: self._stale_closures,
                 })
             }
             _ => PlayerState::Failed(Failed {
                 err: format!("Invalid message {:?} in Uninitialized state", msg),
-                _stale_closures: vec![],
+                _stale_closures: self._stale_closures,
             }),
         }
     }
@@ -76,6 +337,11 @@ impl FsmState for Uninitialized {
 struct Initialized {
     ext_player: Rc<ext::Player>,
     on_ready: Rc<Closure<dyn FnMut(JsValue)>>,
+    pending_commands: Vec<PlayerCommand>,
+    // Closures carried over from a player that's being torn down for
+    // reconstruction (see `PropsDelta::Reinit`); threaded through to `Ready`
+    // once this player reaches it. See the matching field on `Uninitialized`.
+    _stale_closures: Vec<Rc<Closure<dyn FnMut(JsValue)>>>,
 }
 
 impl FsmState for Initialized {
@@ -86,25 +352,148 @@ impl FsmState for Initialized {
                     Ok(new_state) => Msg::PlayerStateChange(new_state),
                     Err(e) => Msg::Failed(e),
                 });
-
                 let closure_state_change = Rc::new(Closure::new(move |s: JsValue| {
                     callback_state_change.emit(s.try_into());
                 }));
 
+                let callback_error = ctx.link().callback(|x| match x {
+                    Ok(err) => Msg::Error(err),
+                    Err(e) => Msg::Failed(e),
+                });
+                let closure_error = Rc::new(Closure::new(move |s: JsValue| {
+                    callback_error.emit(s.try_into());
+                }));
+
+                let callback_quality_change = ctx.link().callback(Msg::PlaybackQualityChange);
+                let closure_quality_change = Rc::new(Closure::new(move |s: JsValue| {
+                    // An unrecognized quality string can't be turned into a
+                    // `VideoQuality` to emit; same as the adaptive quality
+                    // controller, there's nothing useful to do but skip it.
+                    if let Some(quality) = js_sys::Reflect::get(&s, &"data".into())
+                        .ok()
+                        .and_then(|v| v.as_string())
+                        .and_then(|s| ext::VideoQuality::try_from(s.as_str()).ok())
+                    {
+                        callback_quality_change.emit(quality);
+                    }
+                }));
+
+                let callback_rate_change = ctx.link().callback(|s: JsValue| {
+                    Msg::PlaybackRateChange(
+                        js_sys::Reflect::get(&s, &"data".into())
+                            .ok()
+                            .and_then(|v| v.as_f64())
+                            .unwrap_or_default(),
+                    )
+                });
+                let closure_rate_change = Rc::new(Closure::new(move |s: JsValue| {
+                    callback_rate_change.emit(s);
+                }));
+
                 self.ext_player
                     .add_event_listener("onStateChange", closure_state_change.as_ref());
+                self.ext_player
+                    .add_event_listener("onError", closure_error.as_ref());
+                self.ext_player.add_event_listener(
+                    "onPlaybackQualityChange",
+                    closure_quality_change.as_ref(),
+                );
+                self.ext_player
+                    .add_event_listener("onPlaybackRateChange", closure_rate_change.as_ref());
                 self.ext_player
                     .remove_event_listener("onReady", self.on_ready.as_ref());
 
+                if let Some(quality) = ctx.props().suggested_quality {
+                    self.ext_player.set_playback_quality(quality.as_str());
+                }
+
+                // A `PropsDelta::Size` (or a size bundled with a `VideoId`)
+                // that arrived while still `Initialized` has nowhere to land
+                // - there's no iframe to resize until now - so apply
+                // whatever width/height are current as of this transition
+                // rather than whatever they were when construction started.
+                let iframe = self.ext_player.get_iframe();
+                if let Some(width) = ctx.props().width {
+                    iframe.set_width(&width.to_string());
+                }
+                if let Some(height) = ctx.props().height {
+                    iframe.set_height(&height.to_string());
+                }
+
+                let quality_controller = ctx
+                    .props()
+                    .adaptive_quality
+                    .then(|| Rc::new(start_adaptive_quality(self.ext_player.clone(), ctx)));
+
+                for command in self.pending_commands {
+                    dispatch_command(&self.ext_player, command);
+                }
+
                 PlayerState::Ready(Ready {
                     ext_player: self.ext_player,
                     on_state_change: closure_state_change,
+                    on_error: closure_error,
+                    on_playback_quality_change: closure_quality_change,
+                    on_playback_rate_change: closure_rate_change,
+                    quality_controller,
+                    progress_poller: None,
+                    _stale_closures: self._stale_closures,
+                })
+            }
+            Msg::Command(command) => {
+                let mut pending_commands = self.pending_commands;
+                pending_commands.push(command);
+                PlayerState::Initialized(Initialized {
+                    pending_commands,
+                    ..self
+                })
+            }
+            Msg::PropsChanged(old_props) => match diff_props(&old_props, ctx.props()) {
+                PropsDelta::Reinit => {
+                    self.ext_player
+                        .remove_event_listener("onReady", self.on_ready.as_ref());
+                    ctx.link().send_message(Msg::Remount);
+                    let mut _stale_closures = self._stale_closures;
+                    _stale_closures.push(self.on_ready);
+                    PlayerState::Reinitializing(Reinitializing {
+                        pending_commands: self.pending_commands,
+                        _stale_closures,
+                    })
+                }
+                // The player isn't ready to take commands yet; queue the load
+                // so it runs as soon as `onReady` fires. There's no iframe to
+                // resize yet either, so any size bundled alongside the video
+                // id is dropped here the same as it would be on its own (see
+                // the `Size` arm below) - `Ready` is where a size change
+                // actually gets applied.
+                PropsDelta::VideoId { video_id, .. } => {
+                    let mut pending_commands = self.pending_commands;
+                    pending_commands.push(PlayerCommand::LoadVideoById(video_id));
+                    PlayerState::Initialized(Initialized {
+                        pending_commands,
+                        ..self
+                    })
+                }
+                // No iframe to resize until the player signals `onReady`.
+                PropsDelta::Size { .. } | PropsDelta::None => PlayerState::Initialized(self),
+            },
+            // See the matching arm on `Uninitialized`: a stale closure or
+            // poller from a torn-down player describes nothing about the one
+            // under construction here.
+            Msg::PlayerStateChange(_)
+            | Msg::Error(_)
+            | Msg::PlaybackQualityChange(_)
+            | Msg::QualityChange(_)
+            | Msg::PlaybackRateChange(_)
+            | Msg::Progress(_) => PlayerState::Initialized(self),
+            _ => {
+                let mut _stale_closures = self._stale_closures;
+                _stale_closures.push(self.on_ready);
+                PlayerState::Failed(Failed {
+                    err: format!("Invalid message {:?} in Initialized state", msg),
+                    _stale_closures,
                 })
             }
-            _ => PlayerState::Failed(Failed {
-                err: format!("Invalid message {:?} in Initialized state", msg),
-                _stale_closures: vec![self.on_ready],
-            }),
         }
     }
 }
@@ -113,26 +502,204 @@ impl FsmState for Initialized {
 struct Ready {
     ext_player: Rc<ext::Player>,
     on_state_change: Rc<Closure<dyn FnMut(JsValue)>>,
+    on_error: Rc<Closure<dyn FnMut(JsValue)>>,
+    on_playback_quality_change: Rc<Closure<dyn FnMut(JsValue)>>,
+    on_playback_rate_change: Rc<Closure<dyn FnMut(JsValue)>>,
+    // Dropped (and so cancelled) along with the rest of `Ready` on any
+    // transition out of this state; `None` unless `adaptive_quality` is set.
+    quality_controller: Option<Rc<Interval>>,
+    // Only running while the last observed state was `Playing`; dropped (and
+    // so cancelled) on any other state change (`Paused`, `Ended`,
+    // `Buffering`, ...), same as on any exit from `Ready`.
+    progress_poller: Option<Rc<Interval>>,
+    // Closures carried over from a player that was torn down for
+    // reconstruction (see `PropsDelta::Reinit` on `Initialized`), kept alive
+    // past this player's own construction for the same reason as every other
+    // state's field of the same name.
+    _stale_closures: Vec<Rc<Closure<dyn FnMut(JsValue)>>>,
+}
+
+impl Ready {
+    fn remove_listeners(&self) {
+        self.ext_player
+            .remove_event_listener("onStateChange", self.on_state_change.as_ref());
+        self.ext_player
+            .remove_event_listener("onError", self.on_error.as_ref());
+        self.ext_player.remove_event_listener(
+            "onPlaybackQualityChange",
+            self.on_playback_quality_change.as_ref(),
+        );
+        self.ext_player.remove_event_listener(
+            "onPlaybackRateChange",
+            self.on_playback_rate_change.as_ref(),
+        );
+    }
+
+    fn stale_closures(self) -> Vec<Rc<Closure<dyn FnMut(JsValue)>>> {
+        let mut closures = self._stale_closures;
+        closures.extend([
+            self.on_state_change,
+            self.on_error,
+            self.on_playback_quality_change,
+            self.on_playback_rate_change,
+        ]);
+        closures
+    }
 }
 
 impl FsmState for Ready {
     fn transition(self, msg: Msg, ctx: &Context<Player>) -> PlayerState {
         match msg {
             Msg::PlayerStateChange(s) => {
+                let entered_playing = matches!(s, ext::PlayerState::Playing);
+
                 if let Some(cb) = &ctx.props().on_state_change {
                     cb.emit(s);
                 }
+
+                let progress_poller = if entered_playing {
+                    Some(Rc::new(start_progress_poller(self.ext_player.clone(), ctx)))
+                } else {
+                    None
+                };
+
+                PlayerState::Ready(Ready {
+                    progress_poller,
+                    ..self
+                })
+            }
+            Msg::Progress(progress) => {
+                if let Some(cb) = &ctx.props().on_progress {
+                    cb.emit(progress);
+                }
                 PlayerState::Ready(self)
             }
+            Msg::Error(err) => {
+                if let Some(cb) = &ctx.props().on_error {
+                    cb.emit(err);
+                }
+                PlayerState::Ready(self)
+            }
+            Msg::PlaybackQualityChange(quality) => {
+                if let Some(cb) = &ctx.props().on_playback_quality_change {
+                    cb.emit(quality);
+                }
+                PlayerState::Ready(self)
+            }
+            Msg::PlaybackRateChange(rate) => {
+                if let Some(cb) = &ctx.props().on_playback_rate_change {
+                    cb.emit(rate);
+                }
+                PlayerState::Ready(self)
+            }
+            Msg::QualityChange(quality) => {
+                if let Some(cb) = &ctx.props().on_quality_change {
+                    cb.emit(quality);
+                }
+                PlayerState::Ready(self)
+            }
+            Msg::Command(command) => {
+                dispatch_command(&self.ext_player, command);
+                PlayerState::Ready(self)
+            }
+            Msg::PropsChanged(old_props) => match diff_props(&old_props, ctx.props()) {
+                PropsDelta::Reinit => {
+                    self.remove_listeners();
+                    ctx.link().send_message(Msg::Remount);
+                    PlayerState::Reinitializing(Reinitializing {
+                        pending_commands: vec![],
+                        _stale_closures: self.stale_closures(),
+                    })
+                }
+                PropsDelta::VideoId {
+                    video_id,
+                    width,
+                    height,
+                } => {
+                    self.ext_player
+                        .load_video_by_id(JsValue::from_str(&video_id));
+                    // `loadVideoById` doesn't resize the iframe, so a size
+                    // change bundled with the new video still needs applying.
+                    let iframe = self.ext_player.get_iframe();
+                    if let Some(width) = width {
+                        iframe.set_width(&width.to_string());
+                    }
+                    if let Some(height) = height {
+                        iframe.set_height(&height.to_string());
+                    }
+                    PlayerState::Ready(self)
+                }
+                PropsDelta::Size { width, height } => {
+                    let iframe = self.ext_player.get_iframe();
+                    if let Some(width) = width {
+                        iframe.set_width(&width.to_string());
+                    }
+                    if let Some(height) = height {
+                        iframe.set_height(&height.to_string());
+                    }
+                    PlayerState::Ready(self)
+                }
+                PropsDelta::None => PlayerState::Ready(self),
+            },
             _ => {
                 // ISSUE: this doesn't work. I think event listeners need to be registered via name to be removed.
-                self.ext_player
-                    .remove_event_listener("onStateChange", self.on_state_change.as_ref());
+                self.remove_listeners();
                 PlayerState::Failed(Failed {
                     err: format!("Invalid message {:?} in Ready state", msg),
-                    _stale_closures: vec![self.on_state_change],
+                    _stale_closures: self.stale_closures(),
+                })
+            }
+        }
+    }
+}
+
+/// A brief waypoint between tearing down a player for `PropsDelta::Reinit`
+/// and constructing its replacement. On its own, going straight back to
+/// `Uninitialized` leaves `view()` rendering the same wrapper `<div>` it was
+/// already rendering, so yew never actually unmounts the DOM the IFrame API
+/// replaced with an `<iframe>` - the next `ext::Player::new` then targets a
+/// placeholder id that's already taken. Routing through this state first
+/// forces a render of a different wrapper element (the same trick `Failed`
+/// uses), which makes yew tear the old subtree down for real.
+#[derive(Clone)]
+struct Reinitializing {
+    pending_commands: Vec<PlayerCommand>,
+    _stale_closures: Vec<Rc<Closure<dyn FnMut(JsValue)>>>,
+}
+
+impl FsmState for Reinitializing {
+    fn transition(self, msg: Msg, ctx: &Context<Player>) -> PlayerState {
+        match msg {
+            // The remount render has happened; pick back up where
+            // `Uninitialized` left off before `Reinit` was requested.
+            Msg::Remount => {
+                ctx.link().send_message(Msg::Initialized);
+                PlayerState::Uninitialized(Uninitialized {
+                    pending_commands: self.pending_commands,
+                    _stale_closures: self._stale_closures,
+                })
+            }
+            Msg::Command(command) => {
+                let mut pending_commands = self.pending_commands;
+                pending_commands.push(command);
+                PlayerState::Reinitializing(Reinitializing {
+                    pending_commands,
+                    ..self
                 })
             }
+            // A stale closure or poller from the player being torn down can
+            // still fire before it's actually cleaned up; there's no player
+            // here for it to describe.
+            Msg::PlayerStateChange(_)
+            | Msg::Error(_)
+            | Msg::PlaybackQualityChange(_)
+            | Msg::QualityChange(_)
+            | Msg::PlaybackRateChange(_)
+            | Msg::Progress(_) => PlayerState::Reinitializing(self),
+            _ => PlayerState::Failed(Failed {
+                err: format!("Invalid message {:?} in Reinitializing state", msg),
+                _stale_closures: self._stale_closures,
+            }),
         }
     }
 }
@@ -155,6 +722,7 @@ enum PlayerState {
     Uninitialized(Uninitialized),
     Initialized(Initialized),
     Ready(Ready),
+    Reinitializing(Reinitializing),
     Failed(Failed),
 }
 
@@ -164,6 +732,7 @@ impl FsmState for PlayerState {
             PlayerState::Uninitialized(s) => s.transition(msg, ctx),
             PlayerState::Initialized(s) => s.transition(msg, ctx),
             PlayerState::Ready(s) => s.transition(msg, ctx),
+            PlayerState::Reinitializing(s) => s.transition(msg, ctx),
             PlayerState::Failed(s) => s.transition(msg, ctx),
         }
     }
@@ -176,8 +745,18 @@ pub struct Player {
 #[derive(Debug)]
 pub enum Msg {
     Initialized,
+    // Sent once a `PropsDelta::Reinit` has forced a `Reinitializing` render,
+    // to pick the teardown back up into a fresh `Uninitialized`.
+    Remount,
     Ready,
     PlayerStateChange(ext::PlayerState),
+    Error(ext::PlayerError),
+    PlaybackQualityChange(ext::VideoQuality),
+    PlaybackRateChange(f64),
+    QualityChange(ext::VideoQuality),
+    Progress(ext::PlaybackProgress),
+    Command(PlayerCommand),
+    PropsChanged(Props),
     Failed(JsValue),
 }
 
@@ -185,25 +764,42 @@ impl yew::Component for Player {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        let cb: Callback<()> = _ctx.link().callback(|_| Msg::Initialized);
+    fn create(ctx: &Context<Self>) -> Self {
+        let cb: Callback<()> = ctx.link().callback(|_| Msg::Initialized);
         spawn_local(ext::yt_iframe_api_ready().map(move |_| {
             cb.emit(());
         }));
 
+        if let Some(handle) = &ctx.props().handle {
+            handle.bind(ctx.link().callback(Msg::Command));
+        }
+
         Self {
-            state: PlayerState::Uninitialized(Uninitialized),
+            state: PlayerState::Uninitialized(Uninitialized::default()),
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let was_remounting = matches!(
+            self.state,
+            PlayerState::Failed(_) | PlayerState::Reinitializing(_)
+        );
         self.state = self.state.clone().transition(msg, ctx);
+        let is_remounting = matches!(
+            self.state,
+            PlayerState::Failed(_) | PlayerState::Reinitializing(_)
+        );
 
-        // Only rerender if we're in a failed state
-        match &self.state {
-            PlayerState::Failed(_) => true,
-            _ => false,
-        }
+        // `view()` only renders anything different for `Failed`/
+        // `Reinitializing` (the rest all produce the same wrapper `<div>`),
+        // so only rerender on the way into or out of one of those - notably
+        // including the swap back to the normal wrapper, since that's what
+        // makes the `<span>`/`<div>` trick actually force a remount.
+        was_remounting != is_remounting
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        self.update(ctx, Msg::PropsChanged(old_props.clone()))
     }
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
@@ -217,6 +813,13 @@ impl yew::Component for Player {
                 </span>
             },
 
+            // Same `<span>` trick as `Failed`, just empty - its only job is
+            // to be a different top-level element than the wrapper `<div>`
+            // below so yew actually tears the old one down.
+            PlayerState::Reinitializing(_) => html! {
+                <span id="youtube-player-reinit-wrapper"></span>
+            },
+
             _ => html! {
                 <div id="youtube-player-wrapper">
                     <div id="youtube-player-placeholder"></div>
@@ -2,7 +2,7 @@ use js_sys::Function;
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::HtmlScriptElement;
+use web_sys::{HtmlIFrameElement, HtmlScriptElement};
 
 pub async fn load_iframe_api() -> Result<JsValue, JsValue> {
     let window = web_sys::window().ok_or("No global `window` exists")?;
@@ -141,6 +141,100 @@ impl TryFrom<JsValue> for PlayerState {
     }
 }
 
+/// A classified `onError` event from the IFrame API. See
+/// https://developers.google.com/youtube/iframe_api_reference#onError for
+/// the numeric codes this maps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayerError {
+    InvalidParameter,
+    Html5Error,
+    VideoNotFound,
+    EmbeddingDisallowed,
+    Unknown(i32),
+}
+
+impl TryFrom<JsValue> for PlayerError {
+    type Error = JsValue;
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        let code = js_sys::Reflect::get(&value, &"data".into())?
+            .as_f64()
+            .ok_or("invalid player error")?;
+        Ok(match code as i32 {
+            2 => PlayerError::InvalidParameter,
+            5 => PlayerError::Html5Error,
+            100 => PlayerError::VideoNotFound,
+            101 | 150 => PlayerError::EmbeddingDisallowed,
+            other => PlayerError::Unknown(other),
+        })
+    }
+}
+
+/// A playback quality level, as reported by and accepted back into the
+/// IFrame API. See
+/// https://developers.google.com/youtube/iframe_api_reference#Playback_quality
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoQuality {
+    Small,
+    Medium,
+    Large,
+    Hd720,
+    Hd1080,
+    Highres,
+    Default,
+    Auto,
+}
+
+impl VideoQuality {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoQuality::Small => "small",
+            VideoQuality::Medium => "medium",
+            VideoQuality::Large => "large",
+            VideoQuality::Hd720 => "hd720",
+            VideoQuality::Hd1080 => "hd1080",
+            VideoQuality::Highres => "highres",
+            VideoQuality::Default => "default",
+            VideoQuality::Auto => "auto",
+        }
+    }
+}
+
+impl TryFrom<&str> for VideoQuality {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "small" => VideoQuality::Small,
+            "medium" => VideoQuality::Medium,
+            "large" => VideoQuality::Large,
+            "hd720" => VideoQuality::Hd720,
+            "hd1080" => VideoQuality::Hd1080,
+            "highres" => VideoQuality::Highres,
+            "default" => VideoQuality::Default,
+            "auto" => VideoQuality::Auto,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<JsValue> for VideoQuality {
+    type Error = ();
+
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        value.as_string().ok_or(())?.as_str().try_into()
+    }
+}
+
+/// A sample of where playback currently stands, reported on a timer while
+/// the player is playing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaybackProgress {
+    pub current: f64,
+    pub duration: f64,
+    pub loaded_fraction: f64,
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = YT, js_name=Player)]
@@ -152,6 +246,51 @@ extern "C" {
     #[wasm_bindgen(method, js_name=playVideo)]
     pub fn play_video(this: &Player);
 
+    #[wasm_bindgen(method, js_name=pauseVideo)]
+    pub fn pause_video(this: &Player);
+
+    #[wasm_bindgen(method, js_name=stopVideo)]
+    pub fn stop_video(this: &Player);
+
+    #[wasm_bindgen(method, js_name=seekTo)]
+    pub fn seek_to(this: &Player, seconds: f64, allow_seek_ahead: bool);
+
+    #[wasm_bindgen(method, js_name=setVolume)]
+    pub fn set_volume(this: &Player, volume: u8);
+
+    #[wasm_bindgen(method, js_name=mute)]
+    pub fn mute(this: &Player);
+
+    #[wasm_bindgen(method, js_name=unMute)]
+    pub fn un_mute(this: &Player);
+
+    #[wasm_bindgen(method, js_name=setPlaybackRate)]
+    pub fn set_playback_rate(this: &Player, rate: f64);
+
+    #[wasm_bindgen(method, js_name=loadVideoById)]
+    pub fn load_video_by_id(this: &Player, video_id: JsValue);
+
+    #[wasm_bindgen(method, js_name=getIframe)]
+    pub fn get_iframe(this: &Player) -> HtmlIFrameElement;
+
+    #[wasm_bindgen(method, js_name=getAvailableQualityLevels)]
+    pub fn get_available_quality_levels(this: &Player) -> js_sys::Array;
+
+    #[wasm_bindgen(method, js_name=getPlaybackQuality)]
+    pub fn get_playback_quality(this: &Player) -> String;
+
+    #[wasm_bindgen(method, js_name=setPlaybackQuality)]
+    pub fn set_playback_quality(this: &Player, quality: &str);
+
+    #[wasm_bindgen(method, js_name=getVideoLoadedFraction)]
+    pub fn get_video_loaded_fraction(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, js_name=getCurrentTime)]
+    pub fn get_current_time(this: &Player) -> f64;
+
+    #[wasm_bindgen(method, js_name=getDuration)]
+    pub fn get_duration(this: &Player) -> f64;
+
     #[wasm_bindgen(method, js_name=cueVideoById)]
     pub fn cue_video_by_id(this: &Player, video_id: JsValue);
 
@@ -170,15 +309,15 @@ extern "C" {
 }
 
 impl Player {
-    pub fn get_player_state(&self) -> PlayerState {
+    pub fn get_player_state(&self) -> Result<PlayerState, JsValue> {
         match self._get_player_state() {
-            -1 => PlayerState::Unstarted,
-            0 => PlayerState::Ended,
-            1 => PlayerState::Playing,
-            2 => PlayerState::Paused,
-            3 => PlayerState::Buffering,
-            5 => PlayerState::Cued,
-            _ => panic!("unknown player state"),
+            -1 => Ok(PlayerState::Unstarted),
+            0 => Ok(PlayerState::Ended),
+            1 => Ok(PlayerState::Playing),
+            2 => Ok(PlayerState::Paused),
+            3 => Ok(PlayerState::Buffering),
+            5 => Ok(PlayerState::Cued),
+            _ => Err("invalid player state".into()),
         }
     }
 }
@@ -0,0 +1,3 @@
+pub mod component;
+pub mod ext;
+pub mod playlist;
@@ -0,0 +1,164 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::component::{Player, PlayerCommand, PlayerHandle};
+use crate::ext::PlayerState;
+
+/// An imperative command for driving a [`PlaylistPlayer`] from outside,
+/// mirroring `component::PlayerCommand`/`PlayerHandle`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlaylistCommand {
+    Next,
+    Prev,
+    JumpTo(usize),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlaylistHandle(Rc<RefCell<Option<Callback<PlaylistCommand>>>>);
+
+impl PlaylistHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn send(&self, command: PlaylistCommand) {
+        if let Some(cb) = self.0.borrow().as_ref() {
+            cb.emit(command);
+        }
+    }
+
+    fn bind(&self, callback: Callback<PlaylistCommand>) {
+        *self.0.borrow_mut() = Some(callback);
+    }
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub queue: Vec<String>,
+    #[prop_or_default]
+    pub r#loop: bool,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub autoplay: Option<bool>,
+
+    pub on_track_change: Option<Callback<usize>>,
+
+    pub handle: Option<PlaylistHandle>,
+}
+
+pub enum Msg {
+    StateChange(PlayerState),
+    Command(PlaylistCommand),
+}
+
+/// Sequences a queue of video IDs through a single underlying `Player`,
+/// auto-advancing on `Ended` and exposing `next`/`prev`/`jump_to` via a
+/// [`PlaylistHandle`].
+pub struct PlaylistPlayer {
+    player_handle: PlayerHandle,
+    current_index: usize,
+}
+
+impl PlaylistPlayer {
+    fn go_to(&mut self, ctx: &Context<Self>, index: usize) {
+        let queue = &ctx.props().queue;
+        if queue.is_empty() {
+            return;
+        }
+        self.current_index = index.min(queue.len() - 1);
+
+        if let Some(video_id) = queue.get(self.current_index) {
+            self.player_handle
+                .send(PlayerCommand::LoadVideoById(video_id.clone()));
+        }
+        if let Some(cb) = &ctx.props().on_track_change {
+            cb.emit(self.current_index);
+        }
+    }
+
+    fn advance(&mut self, ctx: &Context<Self>) {
+        let len = ctx.props().queue.len();
+        if len == 0 {
+            return;
+        }
+        if self.current_index + 1 < len {
+            self.go_to(ctx, self.current_index + 1);
+        } else if ctx.props().r#loop {
+            self.go_to(ctx, 0);
+        }
+    }
+
+    fn retreat(&mut self, ctx: &Context<Self>) {
+        let len = ctx.props().queue.len();
+        if len == 0 {
+            return;
+        }
+        if self.current_index > 0 {
+            self.go_to(ctx, self.current_index - 1);
+        } else if ctx.props().r#loop {
+            self.go_to(ctx, len - 1);
+        }
+    }
+}
+
+impl Component for PlaylistPlayer {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let player_handle = PlayerHandle::new();
+
+        if let Some(handle) = &ctx.props().handle {
+            handle.bind(ctx.link().callback(Msg::Command));
+        }
+
+        Self {
+            player_handle,
+            current_index: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::StateChange(PlayerState::Ended) => {
+                self.advance(ctx);
+                false
+            }
+            Msg::StateChange(_) => false,
+            Msg::Command(PlaylistCommand::Next) => {
+                self.advance(ctx);
+                false
+            }
+            Msg::Command(PlaylistCommand::Prev) => {
+                self.retreat(ctx);
+                false
+            }
+            Msg::Command(PlaylistCommand::JumpTo(index)) => {
+                self.go_to(ctx, index);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let video_id = ctx
+            .props()
+            .queue
+            .get(self.current_index)
+            .cloned()
+            .unwrap_or_default();
+
+        html! {
+            <Player
+                video_id={video_id}
+                width={ctx.props().width}
+                height={ctx.props().height}
+                autoplay={ctx.props().autoplay}
+                handle={self.player_handle.clone()}
+                on_state_change={ctx.link().callback(Msg::StateChange)}
+            />
+        }
+    }
+}